@@ -0,0 +1,35 @@
+use std::fmt;
+
+/// Errors that can occur while parsing CLI arguments or generating a password
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PasswordGeneratorError {
+    /// No subcommand arguments were provided
+    ArgumentsNotFound,
+    /// The requested subcommand does not exist
+    CommandNotFound,
+    /// An argument was present but could not be parsed into a valid value
+    InvalidArgument,
+    /// The sum of the policy's minimum-per-class counts exceeds the requested length
+    PolicyExceedsLength,
+}
+
+impl fmt::Display for PasswordGeneratorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PasswordGeneratorError::ArgumentsNotFound => {
+                write!(f, "no arguments were provided for the selected subcommand")
+            }
+            PasswordGeneratorError::CommandNotFound => {
+                write!(f, "the selected subcommand does not exist")
+            }
+            PasswordGeneratorError::InvalidArgument => {
+                write!(f, "an argument was present but invalid")
+            }
+            PasswordGeneratorError::PolicyExceedsLength => {
+                write!(f, "the policy's minimum-per-class counts exceed the password length")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PasswordGeneratorError {}
@@ -1,13 +1,49 @@
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+use pbkdf2::pbkdf2_hmac;
 use rand::{rngs::StdRng, Rng, SeedableRng};
+use sha2::Sha256;
 use std::collections::VecDeque;
 
+use crate::error::PasswordGeneratorError;
 use crate::models::InsertDirection;
-pub use crate::models::{PasswordCharRule, DIGITS, LOWERCASE, SYMBOLS, UPPERCASE};
+pub use crate::models::{
+    PasswordCharRule, PasswordClasses, PasswordPolicy, PasswordStrength, Template, WordCase,
+    AMBIGUOUS, DIGITS, LOWERCASE, SYMBOLS, TRIGRAM_WEIGHTS, UPPERCASE, WORDLIST,
+};
+
+const LETTERS: [char; 26] = [
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
+    't', 'u', 'v', 'w', 'x', 'y', 'z',
+];
+
+/// Two-consonant clusters that can legitimately open an English word (`bl`,
+/// `st`, `wh`, ...). `TRIGRAM_WEIGHTS` scores `c2c3` as a mid-word tail, so a
+/// pair like `rd` or `mn` is a fine tail (`hard`, `omnibus`) but not a
+/// plausible word start; `sample_starting_trigram` consults this allowlist
+/// to keep consonant-consonant openings pronounceable.
+const CONSONANT_WORD_STARTS: [(char, char); 30] = [
+    ('b', 'l'), ('b', 'r'), ('c', 'h'), ('c', 'l'), ('c', 'r'), ('d', 'r'), ('d', 'w'),
+    ('f', 'l'), ('f', 'r'), ('g', 'l'), ('g', 'n'), ('g', 'r'), ('k', 'n'), ('p', 'h'),
+    ('p', 'l'), ('p', 'r'), ('q', 'u'), ('s', 'c'), ('s', 'h'), ('s', 'k'), ('s', 'l'),
+    ('s', 'm'), ('s', 'n'), ('s', 'p'), ('s', 't'), ('s', 'w'), ('t', 'h'), ('t', 'r'),
+    ('w', 'h'), ('w', 'r'),
+];
+
+fn is_vowel(letter: usize) -> bool {
+    matches!(LETTERS[letter], 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
 pub struct Generator {
     lower: Vec<char>,
     upper: Vec<char>,
     digit: Vec<char>,
     symbol: Vec<char>,
+    lower_no_ambiguous: Vec<char>,
+    upper_no_ambiguous: Vec<char>,
+    digit_no_ambiguous: Vec<char>,
+    symbol_no_ambiguous: Vec<char>,
+    words: Vec<&'static str>,
     rng: StdRng,
 }
 
@@ -15,29 +51,44 @@ impl Generator {
     pub fn new() -> Self {
         // The random number generator should use the OS entropy for more secure generation
         let rng = StdRng::from_entropy();
+        let lower: Vec<char> = LOWERCASE.chars().collect();
+        let upper: Vec<char> = UPPERCASE.chars().collect();
+        let digit: Vec<char> = DIGITS.chars().collect();
+        let symbol: Vec<char> = SYMBOLS.chars().collect();
+        // The ambiguous-free pools are built once up front so --no-ambiguous
+        // can simply swap in the reduced set without re-filtering per draw
+        let without_ambiguous = |pool: &[char]| -> Vec<char> {
+            pool.iter().copied().filter(|c| !AMBIGUOUS.contains(*c)).collect()
+        };
         Self {
+            lower_no_ambiguous: without_ambiguous(&lower),
+            upper_no_ambiguous: without_ambiguous(&upper),
+            digit_no_ambiguous: without_ambiguous(&digit),
+            symbol_no_ambiguous: without_ambiguous(&symbol),
             // creates character arrays for simpler use
-            lower: LOWERCASE.chars().collect(),
-            upper: UPPERCASE.chars().collect(),
-            digit: DIGITS.chars().collect(),
-            symbol: SYMBOLS.chars().collect(),
+            lower,
+            upper,
+            digit,
+            symbol,
+            words: WORDLIST.lines().collect(),
             rng,
         }
     }
 
-    /// Calculates the strength of the password
-    /// based on the rules applied to the password
-    /// and it's length
+    /// Estimates the strength of a password as Shannon entropy, where `R` is
+    /// the size of the character pool formed by the enabled classes: `bits =
+    /// len * log2(R)`
     ///
     /// ## Arguments
     /// - `len` the password length
-    /// - `with_symbols` whether to include symbols
-    /// - `with_digits` whether to include digits
-    /// - `with_uppercase` whether to include uppercase characters
-    /// - `with_lowercase` whether to include lowercase characters
+    /// - `with_symbols` whether symbols are included
+    /// - `with_numbers` whether digits are included
+    /// - `with_uppercase` whether uppercase characters are included
+    /// - `with_lowercase` whether lowercase characters are included
+    /// - `without_ambiguous` whether ambiguous glyphs are excluded from the pools
     ///
     /// ## Returns
-    /// the password strength percentage
+    /// The entropy-bits estimate along with its labeled rating
     pub fn get_password_strength(
         &self,
         len: u8,
@@ -45,33 +96,120 @@ impl Generator {
         with_numbers: bool,
         with_uppercase: bool,
         with_lowercase: bool,
-    ) -> f64 {
-        if len == 0 {
-            return 0.0_f64;
-        } else if len == 1 {
-            return 0.0_f64;
-        }
-        let max_multiplier: f64 = 32.0 + 10.0 + 26.0 + 26.0; // all possible password options
-
-        // this password options used
-        let mut multiplier: f64 = 0.0;
+        without_ambiguous: bool,
+    ) -> PasswordStrength {
+        let mut pool_size: f64 = 0.0;
         if with_symbols {
-            multiplier += 32.0;
+            pool_size += self.symbol_pool(without_ambiguous).len() as f64;
         }
         if with_numbers {
-            multiplier += 10.0;
+            pool_size += self.digit_pool(without_ambiguous).len() as f64;
         }
         if with_uppercase {
-            multiplier += 26.0;
+            pool_size += self.upper_pool(without_ambiguous).len() as f64;
         }
         if with_lowercase {
-            multiplier += 26.0;
+            pool_size += self.lower_pool(without_ambiguous).len() as f64;
+        }
+
+        let bits = if len == 0 || pool_size == 0.0 {
+            0.0
+        } else {
+            len as f64 * pool_size.log2()
+        };
+
+        PasswordStrength::from_bits(bits)
+    }
+
+    fn symbol_pool(&self, without_ambiguous: bool) -> &[char] {
+        if without_ambiguous {
+            &self.symbol_no_ambiguous
+        } else {
+            &self.symbol
+        }
+    }
+
+    fn digit_pool(&self, without_ambiguous: bool) -> &[char] {
+        if without_ambiguous {
+            &self.digit_no_ambiguous
+        } else {
+            &self.digit
+        }
+    }
+
+    fn upper_pool(&self, without_ambiguous: bool) -> &[char] {
+        if without_ambiguous {
+            &self.upper_no_ambiguous
+        } else {
+            &self.upper
         }
-        // The possible combinations of passwords
-        let a = (len as f64).powf(multiplier);
-        let b: f64 = (255.0_f64).powf(max_multiplier);
-        // wanted to increase the baseline to a min of 20% for low strength
-        20.0 + (a.log(b) * 80.0)
+    }
+
+    fn lower_pool(&self, without_ambiguous: bool) -> &[char] {
+        if without_ambiguous {
+            &self.lower_no_ambiguous
+        } else {
+            &self.lower
+        }
+    }
+
+    /// Estimates the strength of a memorable passphrase as Shannon entropy
+    /// derived from the word-list size and the number of words drawn,
+    /// rather than per-character pool size
+    ///
+    /// ## Arguments
+    /// - `words` the number of words drawn for the passphrase
+    ///
+    /// ## Returns
+    /// The entropy-bits estimate along with its labeled rating
+    pub fn get_passphrase_strength(&self, words: u8) -> PasswordStrength {
+        let bits = if words == 0 || self.words.is_empty() {
+            0.0
+        } else {
+            words as f64 * (self.words.len() as f64).log2()
+        };
+
+        PasswordStrength::from_bits(bits)
+    }
+
+    /// Estimates the strength of a templated password as Shannon entropy,
+    /// summing `log2(R)` over each position's own character pool size `R`
+    /// rather than assuming a single pool shared across the whole password
+    ///
+    /// ## Arguments
+    /// - `template` the per-position character class layout
+    ///
+    /// ## Returns
+    /// The entropy-bits estimate along with its labeled rating
+    pub fn get_template_strength(&self, template: &Template) -> PasswordStrength {
+        let bits: f64 = template
+            .iter()
+            .map(|rule| {
+                let pool_size = match rule {
+                    PasswordCharRule::Symbols => self.symbol.len(),
+                    PasswordCharRule::Digit => self.digit.len(),
+                    PasswordCharRule::Upper => self.upper.len(),
+                    PasswordCharRule::Lower => self.lower.len(),
+                } as f64;
+                pool_size.log2()
+            })
+            .sum();
+
+        PasswordStrength::from_bits(bits)
+    }
+
+    /// Estimates the strength of a pronounceable password as a conservative
+    /// upper bound: the trigram model correlates consecutive letters, so the
+    /// true entropy is lower than a uniformly random lowercase password of
+    /// the same length, but that's the closest simple bound we have
+    ///
+    /// ## Arguments
+    /// - `len` the password length
+    ///
+    /// ## Returns
+    /// The entropy-bits estimate along with its labeled rating
+    pub fn get_pronounceable_strength(&self, len: u8) -> PasswordStrength {
+        self.get_password_strength(len, false, false, false, true, false)
     }
 
     /// Generates a password with the provided rules
@@ -82,6 +220,7 @@ impl Generator {
     /// - `with_digits` whether to include digits
     /// - `with_uppercase` whether to include uppercase characters
     /// - `with_lowercase` whether to include lowercase characters
+    /// - `without_ambiguous` whether to exclude visually ambiguous characters
     ///
     /// ## Returns
     /// The generated password
@@ -92,6 +231,7 @@ impl Generator {
         with_numbers: bool,
         with_uppercase: bool,
         with_lowercase: bool,
+        without_ambiguous: bool,
     ) -> String {
         let password_rules = self.generate_password_rules(
             len,
@@ -100,7 +240,95 @@ impl Generator {
             with_uppercase,
             with_lowercase,
         );
-        self.fill_password(password_rules.iter())
+        self.fill_password(password_rules.iter(), without_ambiguous)
+    }
+
+    /// Generates a password enforcing a minimum number of characters per
+    /// class (e.g. "at least 2 digits and at least 1 symbol"), which the
+    /// plain round-robin `generate_password` cannot express
+    ///
+    /// ## Arguments
+    /// - `len` the length of the password
+    /// - `policy` the enabled classes and their per-class minimum counts
+    /// - `without_ambiguous` whether to exclude visually ambiguous characters
+    ///
+    /// ## Returns
+    /// The generated password, or a `PolicyExceedsLength` error if the
+    /// minimums add up to more than `len`
+    pub fn generate_password_with_policy(
+        &mut self,
+        len: u8,
+        policy: PasswordPolicy,
+        without_ambiguous: bool,
+    ) -> Result<String, PasswordGeneratorError> {
+        let PasswordPolicy {
+            classes,
+            min_symbols,
+            min_digits,
+            min_upper,
+            min_lower,
+        } = policy;
+
+        let total_min = min_symbols as u16
+            + min_digits as u16
+            + min_upper as u16
+            + min_lower as u16;
+        if total_min > len as u16 {
+            return Err(PasswordGeneratorError::PolicyExceedsLength);
+        }
+
+        // A non-zero minimum implies the class is enabled, even if the
+        // corresponding `with_*` flag was left off
+        let with_symbols = classes.with_symbols || min_symbols > 0;
+        let with_numbers = classes.with_numbers || min_digits > 0;
+        let with_uppercase = classes.with_uppercase || min_upper > 0;
+        let with_lowercase = classes.with_lowercase || min_lower > 0;
+
+        // Reserve the requested minimums first
+        let mut password_char_rules_unsorted = Vec::with_capacity(len as usize);
+        password_char_rules_unsorted
+            .extend(std::iter::repeat_n(PasswordCharRule::Symbols, min_symbols as usize));
+        password_char_rules_unsorted
+            .extend(std::iter::repeat_n(PasswordCharRule::Digit, min_digits as usize));
+        password_char_rules_unsorted
+            .extend(std::iter::repeat_n(PasswordCharRule::Lower, min_lower as usize));
+        password_char_rules_unsorted
+            .extend(std::iter::repeat_n(PasswordCharRule::Upper, min_upper as usize));
+
+        // Fill the remaining slots by cycling the enabled classes, same as
+        // the plain round-robin generator
+        let mut distributed_rules = VecDeque::new();
+        if with_symbols {
+            distributed_rules.push_back(PasswordCharRule::Symbols);
+        }
+        if with_numbers {
+            distributed_rules.push_back(PasswordCharRule::Digit);
+        }
+        if with_lowercase {
+            distributed_rules.push_back(PasswordCharRule::Lower);
+        }
+        if with_uppercase {
+            distributed_rules.push_back(PasswordCharRule::Upper);
+        }
+        let mut remaining = len as u16 - total_min;
+        while remaining > 0 {
+            if let Some(next) = distributed_rules.pop_front() {
+                password_char_rules_unsorted.push(next);
+                distributed_rules.push_back(next);
+            }
+            remaining -= 1;
+        }
+
+        // Random sorting of the password rules, same shuffle as generate_password_rules
+        let mut password_char_rules = VecDeque::new();
+        for rule in password_char_rules_unsorted {
+            match self.get_random_element(&[InsertDirection::Back, InsertDirection::Front]) {
+                InsertDirection::Back => password_char_rules.push_back(rule),
+                InsertDirection::Front => password_char_rules.push_front(rule),
+            }
+        }
+
+        Ok(self.fill_password(password_char_rules.iter(), without_ambiguous))
     }
 
     /// Creates a collection of password character rules
@@ -163,17 +391,30 @@ impl Generator {
     ///
     /// ## Arguments
     /// - `char_rules` An iterator over Password char rules to fill
+    /// - `without_ambiguous` whether to draw from the ambiguous-free pools
     ///
     /// ## Returns
     /// A complete password
     fn fill_password<'a>(
         &mut self,
         password_rules: impl Iterator<Item = &'a PasswordCharRule>,
+        without_ambiguous: bool,
     ) -> String {
-        let lower = self.lower.clone();
-        let upper = self.upper.clone();
-        let digit = self.digit.clone();
-        let symbol = self.symbol.clone();
+        let (lower, upper, digit, symbol) = if without_ambiguous {
+            (
+                self.lower_no_ambiguous.clone(),
+                self.upper_no_ambiguous.clone(),
+                self.digit_no_ambiguous.clone(),
+                self.symbol_no_ambiguous.clone(),
+            )
+        } else {
+            (
+                self.lower.clone(),
+                self.upper.clone(),
+                self.digit.clone(),
+                self.symbol.clone(),
+            )
+        };
         password_rules
             .map(move |rule| match rule {
                 PasswordCharRule::Upper => self.get_random_element(&upper),
@@ -184,6 +425,269 @@ impl Generator {
             .collect::<String>()
     }
 
+    /// Generates a memorable passphrase by drawing words from the embedded
+    /// Diceware-style word list, rather than driving the length off of a
+    /// per-character count
+    ///
+    /// ## Arguments
+    /// - `words` the number of words to draw
+    /// - `separator` the string inserted between each word
+    /// - `word_case` the casing applied to every drawn word
+    /// - `with_digit` whether to insert a random digit at a random word boundary
+    /// - `with_symbol` whether to insert a random symbol at a random word boundary
+    ///
+    /// ## Returns
+    /// The generated passphrase
+    pub fn generate_passphrase(
+        &mut self,
+        words: u8,
+        separator: &str,
+        word_case: WordCase,
+        with_digit: bool,
+        with_symbol: bool,
+    ) -> String {
+        let word_pool = self.words.clone();
+        let mut parts: Vec<String> = (0..words)
+            .map(|_| {
+                let word = self.get_random_element(&word_pool);
+                match word_case {
+                    WordCase::Lower => word.to_lowercase(),
+                    WordCase::Upper => word.to_uppercase(),
+                    WordCase::Capitalize => capitalize(word),
+                }
+            })
+            .collect();
+
+        if with_digit {
+            let digit = self.get_random_element(&self.digit.clone());
+            let index = self.rng.gen_range(0..=parts.len());
+            parts.insert(index, digit.to_string());
+        }
+        if with_symbol {
+            let symbol = self.get_random_element(&self.symbol.clone());
+            let index = self.rng.gen_range(0..=parts.len());
+            parts.insert(index, symbol.to_string());
+        }
+
+        parts.join(separator)
+    }
+
+    /// Generates a pronounceable password using an order-2 Markov (trigram)
+    /// model, similar to the classic GPW generator, so the result stays easy
+    /// to say and remember while still being randomly drawn
+    ///
+    /// ## Arguments
+    /// - `len` the length of the password
+    ///
+    /// ## Returns
+    /// The generated pronounceable password
+    pub fn generate_pronounceable(&mut self, len: u8) -> String {
+        if len == 0 {
+            return String::new();
+        }
+
+        let (first, second, third) = self.sample_starting_trigram();
+        let mut result = String::new();
+        result.push(LETTERS[first]);
+        if len >= 2 {
+            result.push(LETTERS[second]);
+        }
+        if len >= 3 {
+            result.push(LETTERS[third]);
+        }
+
+        let mut last_two = (second, third);
+        while (result.len() as u8) < len {
+            match self.sample_next_letter(last_two.0, last_two.1) {
+                Some(next) => {
+                    result.push(LETTERS[next]);
+                    last_two = (last_two.1, next);
+                }
+                None => {
+                    // The current context has no known continuation, restart
+                    // from a freshly sampled trigram
+                    let (a, b, c) = self.sample_starting_trigram();
+                    result.push(LETTERS[a]);
+                    result.push(LETTERS[b]);
+                    result.push(LETTERS[c]);
+                    last_two = (b, c);
+                }
+            }
+        }
+
+        result.truncate(len as usize);
+        result
+    }
+
+    /// Picks a starting trigram by sampling uniformly over the whole
+    /// flattened `TRIGRAM_WEIGHTS` table and walking the cumulative weights.
+    /// Resamples, up to 50 times, if the first two letters form a
+    /// consonant-consonant pair that isn't in `CONSONANT_WORD_STARTS`, since
+    /// `TRIGRAM_WEIGHTS` scores that pair as a mid-word tail rather than as a
+    /// word opening. After 50 rejected draws the next trigram is returned
+    /// without that check.
+    ///
+    /// ## Returns
+    /// The three letter indices (`0..26`) of the sampled trigram
+    fn sample_starting_trigram(&mut self) -> (usize, usize, usize) {
+        for _ in 0..50 {
+            let trigram = self.sample_trigram_from_table();
+            let (c1, c2, _) = trigram;
+            let pair = (LETTERS[c1], LETTERS[c2]);
+            if is_vowel(c1) || is_vowel(c2) || CONSONANT_WORD_STARTS.contains(&pair) {
+                return trigram;
+            }
+        }
+        self.sample_trigram_from_table()
+    }
+
+    fn sample_trigram_from_table(&mut self) -> (usize, usize, usize) {
+        let total: u32 = TRIGRAM_WEIGHTS.iter().sum();
+        let mut target = self.rng.gen_range(0..total);
+        for (idx, weight) in TRIGRAM_WEIGHTS.iter().enumerate() {
+            if target < *weight {
+                return (idx / 676, (idx / 26) % 26, idx % 26);
+            }
+            target -= weight;
+        }
+        (0, 0, 0)
+    }
+
+    /// Samples the next letter given the last two emitted letters, drawing a
+    /// uniform value in `[0, sum)` over the 26 weights for that `c1c2`
+    /// context and picking the `c3` whose cumulative weight crosses it
+    ///
+    /// ## Arguments
+    /// - `c1` index of the first letter of the context
+    /// - `c2` index of the second letter of the context
+    ///
+    /// ## Returns
+    /// The sampled letter index, or `None` if the context has zero weight
+    fn sample_next_letter(&mut self, c1: usize, c2: usize) -> Option<usize> {
+        let base = c1 * 676 + c2 * 26;
+        let weights = &TRIGRAM_WEIGHTS[base..base + 26];
+        let total: u32 = weights.iter().sum();
+        if total == 0 {
+            return None;
+        }
+        let mut target = self.rng.gen_range(0..total);
+        for (c3, weight) in weights.iter().enumerate() {
+            if target < *weight {
+                return Some(c3);
+            }
+            target -= weight;
+        }
+        None
+    }
+
+    /// Deterministically derives a password from a master secret, a site
+    /// name and a counter, LessPass/Master Password style, so nothing needs
+    /// to be stored on disk to reproduce the same password later
+    ///
+    /// ## Arguments
+    /// - `master` the master secret
+    /// - `site` the site (or account) the password is for
+    /// - `counter` a counter allowing multiple passwords per site
+    /// - `len` the length of the password
+    /// - `classes` the character classes the derived password must draw from
+    ///
+    /// ## Returns
+    /// The derived password. The same inputs always produce the same output
+    pub fn derive_password(
+        &self,
+        master: &str,
+        site: &str,
+        counter: u32,
+        len: u8,
+        classes: PasswordClasses,
+    ) -> String {
+        if len == 0 {
+            return String::new();
+        }
+
+        let mut charset: Vec<char> = Vec::new();
+        if classes.with_symbols {
+            charset.extend(self.symbol.iter());
+        }
+        if classes.with_numbers {
+            charset.extend(self.digit.iter());
+        }
+        if classes.with_lowercase {
+            charset.extend(self.lower.iter());
+        }
+        if classes.with_uppercase {
+            charset.extend(self.upper.iter());
+        }
+        if charset.is_empty() {
+            return String::new();
+        }
+
+        let salt = format!("{}{}", site, counter);
+        let mut derived_key = [0u8; 32];
+        pbkdf2_hmac::<Sha256>(master.as_bytes(), salt.as_bytes(), 100_000, &mut derived_key);
+
+        let mut entropy = BigUint::from_bytes_be(&derived_key);
+        let charset_len = BigUint::from(charset.len() as u64);
+
+        let mut password: Vec<char> = Vec::with_capacity(len as usize);
+        for _ in 0..len {
+            let index = (&entropy % &charset_len).to_usize().unwrap_or(0);
+            password.push(charset[index]);
+            entropy /= &charset_len;
+        }
+
+        // Force one character from each required class in, using the
+        // remaining entropy to pick both the character and where it lands.
+        // Each forced character takes a position no earlier class has taken,
+        // as long as such a position remains
+        let class_pools: [(bool, &Vec<char>); 4] = [
+            (classes.with_symbols, &self.symbol),
+            (classes.with_numbers, &self.digit),
+            (classes.with_uppercase, &self.upper),
+            (classes.with_lowercase, &self.lower),
+        ];
+        let mut forced_positions: Vec<usize> = Vec::new();
+        for (enabled, pool) in class_pools {
+            if !enabled || pool.is_empty() {
+                continue;
+            }
+            let free_positions: Vec<usize> = (0..password.len())
+                .filter(|i| !forced_positions.contains(i))
+                .collect();
+            if free_positions.is_empty() {
+                break;
+            }
+            let pool_len = BigUint::from(pool.len() as u64);
+            let char_index = (&entropy % &pool_len).to_usize().unwrap_or(0);
+            entropy /= &pool_len;
+
+            let insert_len = BigUint::from(free_positions.len() as u64);
+            let insert_index =
+                free_positions[(&entropy % &insert_len).to_usize().unwrap_or(0)];
+            entropy /= &insert_len;
+
+            password[insert_index] = pool[char_index];
+            forced_positions.push(insert_index);
+        }
+
+        password.into_iter().collect()
+    }
+
+    /// Generates a password following a fixed per-position layout, a
+    /// superset of the existing pin/random behavior that lets a caller hit
+    /// oddly specific site requirements (e.g. "two uppercase, four
+    /// lowercase, two digits, one symbol") while the content at each
+    /// position is still randomly drawn
+    ///
+    /// ## Arguments
+    /// - `template` the per-position character class layout
+    ///
+    /// ## Returns
+    /// The generated password, one character per template position
+    pub fn generate_from_template(&mut self, template: &Template) -> String {
+        self.fill_password(template.iter(), false)
+    }
+
     /// Selects a random element from the array provided
     /// using a Criptographically secure pseudo rng to determine the index
     /// https://rust-random.github.io/rand/rand/rngs/struct.StdRng.html
@@ -199,11 +703,20 @@ impl Generator {
     }
 }
 
+/// Capitalizes the first character of a word, leaving the rest untouched
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
 #[test]
 fn generate_different_sizes() {
     let mut generator = Generator::new();
     for i in 0..255 {
-        let password = generator.generate_password(i, true, true, true, true);
+        let password = generator.generate_password(i, true, true, true, true, false);
         assert_eq!(password.len() as u8, i, "Password is not the right length");
     }
 }
@@ -211,7 +724,7 @@ fn generate_different_sizes() {
 #[test]
 fn generate_pin() {
     let mut generator = Generator::new();
-    let password = generator.generate_password(5, false, true, false, false);
+    let password = generator.generate_password(5, false, true, false, false, false);
     assert_eq!(password.len(), 5, "Password is not the right length");
     for c in password.chars() {
         assert!(
@@ -224,7 +737,7 @@ fn generate_pin() {
 #[test]
 fn generate_random() {
     let mut generator = Generator::new();
-    let password = generator.generate_password(10, true, true, true, true);
+    let password = generator.generate_password(10, true, true, true, true, false);
     assert_eq!(password.len(), 10, "Password is not the right length");
     assert_password(&password, true, true, true, true);
 }
@@ -232,23 +745,23 @@ fn generate_random() {
 #[test]
 fn generate_with_two_rules() {
     let mut generator = Generator::new();
-    let password = generator.generate_password(10, true, true, false, false);
+    let password = generator.generate_password(10, true, true, false, false, false);
     assert_eq!(password.len(), 10, "Password is not the right length");
     assert_password(&password, true, true, false, false);
 
-    let password = generator.generate_password(10, true, false, true, false);
+    let password = generator.generate_password(10, true, false, true, false, false);
     assert_eq!(password.len(), 10, "Password is not the right length");
     assert_password(&password, true, false, true, false);
 
-    let password = generator.generate_password(10, true, false, false, true);
+    let password = generator.generate_password(10, true, false, false, true, false);
     assert_eq!(password.len(), 10, "Password is not the right length");
     assert_password(&password, true, false, false, true);
 
-    let password = generator.generate_password(10, false, true, true, false);
+    let password = generator.generate_password(10, false, true, true, false, false);
     assert_eq!(password.len(), 10, "Password is not the right length");
     assert_password(&password, false, true, true, false);
 
-    let password = generator.generate_password(10, false, false, true, true);
+    let password = generator.generate_password(10, false, false, true, true, false);
     assert_eq!(password.len(), 10, "Password is not the right length");
     assert_password(&password, false, false, true, true);
 }
@@ -256,7 +769,7 @@ fn generate_with_two_rules() {
 #[test]
 fn generate_with_lower_upper_digit_rules() {
     let mut generator = Generator::new();
-    let password = generator.generate_password(10, false, true, true, true);
+    let password = generator.generate_password(10, false, true, true, true, false);
     assert_eq!(password.len(), 10, "Password is not the right length");
     assert_password(&password, false, true, true, true);
 }
@@ -264,7 +777,7 @@ fn generate_with_lower_upper_digit_rules() {
 #[test]
 fn generate_with_symbol_lower_upper_rules() {
     let mut generator = Generator::new();
-    let password = generator.generate_password(10, true, false, true, true);
+    let password = generator.generate_password(10, true, false, true, true, false);
     assert_eq!(password.len(), 10, "Password is not the right length");
     assert_password(&password, true, false, true, true);
 }
@@ -272,7 +785,7 @@ fn generate_with_symbol_lower_upper_rules() {
 #[test]
 fn generate_with_symbol_lower_digit_rules() {
     let mut generator = Generator::new();
-    let password = generator.generate_password(10, true, true, false, true);
+    let password = generator.generate_password(10, true, true, false, true, false);
     assert_eq!(password.len(), 10, "Password is not the right length");
     assert_password(&password, true, true, false, true);
 }
@@ -280,7 +793,7 @@ fn generate_with_symbol_lower_digit_rules() {
 #[test]
 fn generate_with_symbol_upper_digit_rules() {
     let mut generator = Generator::new();
-    let password = generator.generate_password(10, true, true, true, false);
+    let password = generator.generate_password(10, true, true, true, false, false);
     assert_eq!(password.len(), 10, "Password is not the right length");
     assert_password(&password, true, true, true, false);
 }
@@ -290,7 +803,7 @@ fn generate_unique_passwords() {
     let mut generator = Generator::new();
     let mut previously_generated_passwords = std::collections::HashSet::new();
     for _ in 0..100000 {
-        let password = generator.generate_password(10, true, true, true, false);
+        let password = generator.generate_password(10, true, true, true, false, false);
         assert_eq!(password.len(), 10, "Password is not the right length");
         assert_password(&password, true, true, true, false);
         assert!(
@@ -301,6 +814,176 @@ fn generate_unique_passwords() {
     }
 }
 
+#[test]
+fn generate_passphrase_word_count() {
+    let mut generator = Generator::new();
+    let passphrase = generator.generate_passphrase(4, "-", crate::models::WordCase::Capitalize, false, false);
+    assert_eq!(
+        passphrase.split('-').count(),
+        4,
+        "Passphrase should contain exactly the requested number of words"
+    );
+    for word in passphrase.split('-') {
+        let mut chars = word.chars();
+        let first = chars.next().expect("word should not be empty");
+        assert!(first.is_uppercase(), "Each word should be capitalized");
+    }
+}
+
+#[test]
+fn generate_pronounceable_sizes() {
+    let mut generator = Generator::new();
+    for i in 0..50 {
+        let password = generator.generate_pronounceable(i);
+        assert_eq!(password.len() as u8, i, "Password is not the right length");
+        assert!(
+            password.chars().all(|c| c.is_ascii_lowercase()),
+            "Pronounceable password should only contain lowercase letters"
+        );
+    }
+}
+
+#[test]
+fn derive_password_is_deterministic() {
+    let generator = Generator::new();
+    let classes = crate::models::PasswordClasses {
+        with_symbols: true,
+        with_numbers: true,
+        with_uppercase: true,
+        with_lowercase: true,
+    };
+    let first = generator.derive_password("hunter2", "example.com", 0, 16, classes);
+    let second = generator.derive_password("hunter2", "example.com", 0, 16, classes);
+    assert_eq!(first, second, "Same inputs should derive the same password");
+    assert_eq!(first.len(), 16, "Password is not the right length");
+
+    let different_site = generator.derive_password("hunter2", "other.com", 0, 16, classes);
+    assert_ne!(
+        first, different_site,
+        "Different sites should derive different passwords"
+    );
+}
+
+#[test]
+fn generate_password_without_ambiguous() {
+    let mut generator = Generator::new();
+    for _ in 0..1000 {
+        let password = generator.generate_password(20, true, true, true, true, true);
+        assert_eq!(password.len(), 20, "Password is not the right length");
+        for c in password.chars() {
+            assert!(
+                !crate::models::AMBIGUOUS.contains(c),
+                "Password should not contain ambiguous characters"
+            );
+        }
+    }
+}
+
+#[test]
+fn generate_password_with_policy_respects_minimums() {
+    let mut generator = Generator::new();
+    let policy = crate::models::PasswordPolicy {
+        min_symbols: 1,
+        min_digits: 2,
+        ..Default::default()
+    };
+    let password = generator
+        .generate_password_with_policy(10, policy, false)
+        .expect("policy within length should succeed");
+    assert_eq!(password.len(), 10, "Password is not the right length");
+
+    let digit_count = password
+        .chars()
+        .filter(|c| crate::models::DIGITS.contains(*c))
+        .count();
+    let symbol_count = password
+        .chars()
+        .filter(|c| crate::models::SYMBOLS.contains(*c))
+        .count();
+    assert!(digit_count >= 2, "Password should contain at least 2 digits");
+    assert!(symbol_count >= 1, "Password should contain at least 1 symbol");
+}
+
+#[test]
+fn generate_password_with_policy_rejects_oversized_minimums() {
+    let mut generator = Generator::new();
+    let policy = crate::models::PasswordPolicy {
+        classes: crate::models::PasswordClasses {
+            with_symbols: true,
+            with_numbers: true,
+            with_uppercase: true,
+            with_lowercase: true,
+        },
+        min_symbols: 2,
+        min_digits: 2,
+        min_upper: 2,
+        min_lower: 2,
+    };
+    let result = generator.generate_password_with_policy(4, policy, false);
+    assert!(matches!(
+        result,
+        Err(crate::error::PasswordGeneratorError::PolicyExceedsLength)
+    ));
+}
+
+#[test]
+fn get_password_strength_computes_entropy_bits() {
+    let generator = Generator::new();
+    let strength = generator.get_password_strength(10, true, true, true, true, false);
+    let expected_bits = 10.0_f64 * (94.0_f64).log2();
+    assert!(
+        (strength.bits - expected_bits).abs() < 1e-9,
+        "Entropy bits should match len * log2(pool size)"
+    );
+    assert_eq!(strength.rating, crate::models::StrengthRating::Strong);
+
+    let empty_strength = generator.get_password_strength(10, false, false, false, false, false);
+    assert_eq!(empty_strength.bits, 0.0);
+
+    let without_ambiguous_strength = generator.get_password_strength(10, true, true, true, true, true);
+    assert!(
+        without_ambiguous_strength.bits < strength.bits,
+        "Excluding ambiguous glyphs should shrink the pool and reduce entropy"
+    );
+}
+
+#[test]
+fn get_passphrase_strength_uses_word_list_size() {
+    let generator = Generator::new();
+    let strength = generator.get_passphrase_strength(4);
+    let expected_bits = 4.0_f64 * (136.0_f64).log2();
+    assert!(
+        (strength.bits - expected_bits).abs() < 1e-9,
+        "Passphrase entropy should be derived from the word list size"
+    );
+}
+
+#[test]
+fn get_template_strength_sums_per_position_pools() {
+    let generator = Generator::new();
+    let template = crate::models::Template::parse("Cn");
+    let strength = generator.get_template_strength(&template);
+    let expected_bits = (26.0_f64).log2() + (10.0_f64).log2();
+    assert!(
+        (strength.bits - expected_bits).abs() < 1e-9,
+        "Template entropy should sum log2(pool size) over each position"
+    );
+}
+
+#[test]
+fn generate_from_template_follows_layout() {
+    let mut generator = Generator::new();
+    let template = crate::models::Template::parse("CvccnoCvccn");
+    let password = generator.generate_from_template(&template);
+    assert_eq!(password.len(), 11, "Password should have one character per template position");
+
+    let chars: Vec<char> = password.chars().collect();
+    assert!(crate::models::UPPERCASE.contains(chars[0]));
+    assert!(crate::models::LOWERCASE.contains(chars[1]));
+    assert!(crate::models::DIGITS.contains(chars[4]));
+    assert!(crate::models::SYMBOLS.contains(chars[5]));
+}
+
 #[cfg(test)]
 /// Helper function to assert a password and it's rules
 /// Verifies that at least one character of each applied rule is included
@@ -1,6 +1,7 @@
 use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
 use error::PasswordGeneratorError;
 use generator::Generator;
+use models::{PasswordClasses, PasswordPolicy, Template, WordCase};
 
 pub mod error;
 pub mod generator;
@@ -28,7 +29,61 @@ fn main() -> Result<(), PasswordGeneratorError> {
                 Arg::new("type")
                     .long("type")
                     .action(ArgAction::Set)
-                    .value_parser(["random", "pin", "memorable"]),
+                    .value_parser(["random", "pin", "memorable", "pronounceable", "derived", "template"]),
+                Arg::new("words")
+                    .long("words")
+                    .value_parser(value_parser!(u8))
+                    .action(ArgAction::Set)
+                    .default_value("4"),
+                Arg::new("separator")
+                    .long("separator")
+                    .action(ArgAction::Set)
+                    .default_value("-"),
+                Arg::new("word-case")
+                    .long("word-case")
+                    .action(ArgAction::Set)
+                    .value_parser(["lower", "upper", "capitalize"])
+                    .default_value("capitalize"),
+                Arg::new("master")
+                    .long("master")
+                    .action(ArgAction::Set)
+                    .default_value(""),
+                Arg::new("site")
+                    .long("site")
+                    .action(ArgAction::Set)
+                    .default_value(""),
+                Arg::new("counter")
+                    .long("counter")
+                    .value_parser(value_parser!(u32))
+                    .action(ArgAction::Set)
+                    .default_value("0"),
+                Arg::new("no-ambiguous")
+                    .long("no-ambiguous")
+                    .action(ArgAction::SetTrue),
+                Arg::new("min-digits")
+                    .long("min-digits")
+                    .value_parser(value_parser!(u8))
+                    .action(ArgAction::Set)
+                    .default_value("0"),
+                Arg::new("min-symbols")
+                    .long("min-symbols")
+                    .value_parser(value_parser!(u8))
+                    .action(ArgAction::Set)
+                    .default_value("0"),
+                Arg::new("min-upper")
+                    .long("min-upper")
+                    .value_parser(value_parser!(u8))
+                    .action(ArgAction::Set)
+                    .default_value("0"),
+                Arg::new("min-lower")
+                    .long("min-lower")
+                    .value_parser(value_parser!(u8))
+                    .action(ArgAction::Set)
+                    .default_value("0"),
+                Arg::new("template")
+                    .long("template")
+                    .action(ArgAction::Set)
+                    .default_value(""),
             ]),
         )
         .get_matches();
@@ -68,40 +123,168 @@ fn execute_command_from_args(
         }
     };
 
+    let no_ambiguous = matches.get_flag("no-ambiguous");
+
     match matches
         .get_one::<String>("type")
         .and_then(|s| Some(s.as_str()))
     {
         Some("random") => {
-            let generated_password = generator.generate_password(len, true, true, true, true);
+            let generated_password =
+                generator.generate_password(len, true, true, true, true, no_ambiguous);
             println!("Generated fully random password: {}", generated_password);
+            let strength = generator.get_password_strength(len, true, true, true, true, no_ambiguous);
             eprintln!(
-                "Fully random password's strength: {:.0}%",
-                generator.get_password_strength(len, true, true, true, true)
+                "Fully random password's strength: Entropy: {:.0} bits ({})",
+                strength.bits, strength.rating
             );
             return Ok(());
         }
         Some("pin") => {
-            let generated_password = generator.generate_password(len, false, true, false, false);
+            let generated_password =
+                generator.generate_password(len, false, true, false, false, no_ambiguous);
             println!("Generated pin: {}", generated_password);
+            let strength = generator.get_password_strength(len, false, true, false, false, no_ambiguous);
+            eprintln!(
+                "Generated pin's strength: Entropy: {:.0} bits ({})",
+                strength.bits, strength.rating
+            );
+            return Ok(());
+        }
+        Some("template") => {
+            let pattern = matches
+                .get_one::<String>("template")
+                .map(|s| s.as_str())
+                .unwrap_or("");
+            if pattern.is_empty() {
+                eprintln!("template must not be empty when --type template is selected");
+                return Err(PasswordGeneratorError::InvalidArgument);
+            }
+            let template = Template::parse(pattern);
+            let generated_password = generator.generate_from_template(&template);
+            println!("Generated templated password: {}", generated_password);
+            let strength = generator.get_template_strength(&template);
+            eprintln!(
+                "Templated password's strength: Entropy: {:.0} bits ({})",
+                strength.bits, strength.rating
+            );
+            return Ok(());
+        }
+        Some("derived") => {
+            let master = matches
+                .get_one::<String>("master")
+                .map(|s| s.as_str())
+                .unwrap_or("");
+            let site = matches
+                .get_one::<String>("site")
+                .map(|s| s.as_str())
+                .unwrap_or("");
+            let counter: u32 = match matches.get_one("counter") {
+                Some(counter) => *counter,
+                None => 0,
+            };
+            let is_num = matches.get_flag("numbers");
+            let is_cap = matches.get_flag("capitalized");
+            let is_sym = matches.get_flag("symbols");
+            let classes = PasswordClasses {
+                with_symbols: is_sym,
+                with_numbers: is_num,
+                with_uppercase: is_cap,
+                with_lowercase: true,
+            };
+            let generated_password = generator.derive_password(master, site, counter, len, classes);
+            println!("Generated derived password: {}", generated_password);
+            let strength = generator.get_password_strength(
+                len,
+                classes.with_symbols,
+                classes.with_numbers,
+                classes.with_uppercase,
+                classes.with_lowercase,
+                false,
+            );
+            eprintln!(
+                "Derived password's strength: Entropy: {:.0} bits ({})",
+                strength.bits, strength.rating
+            );
+            return Ok(());
+        }
+        Some("pronounceable") => {
+            let generated_password = generator.generate_pronounceable(len);
+            println!("Generated pronounceable password: {}", generated_password);
+            let strength = generator.get_pronounceable_strength(len);
+            eprintln!(
+                "Pronounceable password's strength: Entropy: {:.0} bits ({})",
+                strength.bits, strength.rating
+            );
+            return Ok(());
+        }
+        Some("memorable") => {
+            let words: u8 = match matches.get_one("words") {
+                Some(words) => *words,
+                None => {
+                    eprintln!("words should be a positive number between 0 and 255");
+                    return Err(PasswordGeneratorError::InvalidArgument);
+                }
+            };
+            let separator = matches
+                .get_one::<String>("separator")
+                .map(|s| s.as_str())
+                .unwrap_or("-");
+            let word_case = match matches.get_one::<String>("word-case").map(|s| s.as_str()) {
+                Some("lower") => WordCase::Lower,
+                Some("upper") => WordCase::Upper,
+                _ => WordCase::Capitalize,
+            };
+            let is_num = matches.get_flag("numbers");
+            let is_sym = matches.get_flag("symbols");
+            let generated_passphrase =
+                generator.generate_passphrase(words, separator, word_case, is_num, is_sym);
+            println!("Generated passphrase: {}", generated_passphrase);
+            let strength = generator.get_passphrase_strength(words);
             eprintln!(
-                "Generated pin's strength: {:.0}%",
-                generator.get_password_strength(len, false, true, false, false)
+                "Passphrase's strength: Entropy: {:.0} bits ({})",
+                strength.bits, strength.rating
             );
             return Ok(());
         }
-        Some("memorable") => unimplemented!(),
         _ => {}
     }
 
     let is_num = matches.get_flag("numbers");
     let is_cap = matches.get_flag("capitalized");
     let is_sym = matches.get_flag("symbols");
-    let generated_password = generator.generate_password(len, is_sym, is_num, is_cap, true);
+
+    let min_digits: u8 = matches.get_one("min-digits").copied().unwrap_or(0);
+    let min_symbols: u8 = matches.get_one("min-symbols").copied().unwrap_or(0);
+    let min_upper: u8 = matches.get_one("min-upper").copied().unwrap_or(0);
+    let min_lower: u8 = matches.get_one("min-lower").copied().unwrap_or(0);
+
+    if min_digits > 0 || min_symbols > 0 || min_upper > 0 || min_lower > 0 {
+        let policy = PasswordPolicy {
+            classes: PasswordClasses {
+                with_symbols: is_sym,
+                with_numbers: is_num,
+                with_uppercase: is_cap,
+                with_lowercase: true,
+            },
+            min_symbols,
+            min_digits,
+            min_upper,
+            min_lower,
+        };
+        let generated_password =
+            generator.generate_password_with_policy(len, policy, no_ambiguous)?;
+        println!("Generated password: {}", generated_password);
+        return Ok(());
+    }
+
+    let generated_password =
+        generator.generate_password(len, is_sym, is_num, is_cap, true, no_ambiguous);
     println!("Generated password: {}", generated_password);
+    let strength = generator.get_password_strength(len, is_sym, is_num, is_cap, true, no_ambiguous);
     eprintln!(
-        "Password's strength: {:.0}%",
-        generator.get_password_strength(len, is_sym, is_num, is_cap, true)
+        "Password's strength: Entropy: {:.0} bits ({})",
+        strength.bits, strength.rating
     );
     Ok(())
 }
@@ -3,6 +3,149 @@ pub const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
 pub const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
 pub const DIGITS: &str = "0123456789";
 
+/// Visually ambiguous glyphs that are easy to confuse when handwritten or
+/// read aloud: zero/capital O, one/lowercase L/capital I, and pipe
+pub const AMBIGUOUS: &str = "0O1lI|";
+
+/// Diceware-style word list used to build memorable passphrases.
+/// One word per line, all lowercase ASCII.
+pub const WORDLIST: &str = "apple
+river
+mountain
+table
+engine
+garden
+silver
+bridge
+forest
+castle
+window
+harbor
+rocket
+violin
+canyon
+meadow
+thunder
+crystal
+falcon
+anchor
+shadow
+blanket
+whisper
+pepper
+jungle
+copper
+maple
+velvet
+ladder
+signal
+marble
+ribbon
+compass
+lantern
+shelter
+voyage
+feather
+granite
+volcano
+orchard
+pebble
+tunnel
+wagon
+saddle
+temple
+island
+sprout
+dagger
+goblin
+cinder
+willow
+prairie
+coral
+ember
+frost
+glacier
+breeze
+cactus
+ripple
+timber
+flannel
+violet
+walnut
+yonder
+zephyr
+quartz
+plume
+thistle
+spindle
+cobalt
+hollow
+saffron
+tinder
+brook
+marsh
+gallant
+harvest
+kettle
+lumber
+nimble
+orchid
+pocket
+quill
+summit
+talon
+umbrella
+vessel
+wicker
+amber
+beacon
+dapple
+flagon
+grotto
+hamlet
+icicle
+jasper
+kindle
+lagoon
+mantle
+nectar
+opal
+pinnacle
+quarry
+rapids
+sable
+tangle
+urchin
+vapor
+wander
+xenon
+zesty
+ash
+birch
+cedar
+dune
+elm
+fable
+glen
+heron
+ivy
+juniper
+kelp
+lynx
+mint
+nettle
+oak
+pine
+quail
+rye
+sage
+teal
+umber
+vine
+wheat
+yew
+zinc";
+
 #[derive(Clone, Copy, Debug)]
 pub enum PasswordCharRule {
     Symbols,
@@ -11,8 +154,836 @@ pub enum PasswordCharRule {
     Digit,
 }
 
+/// The character classes enabled for password generation, bundled so
+/// functions that need all four don't have to take four separate boolean
+/// parameters
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PasswordClasses {
+    pub with_symbols: bool,
+    pub with_numbers: bool,
+    pub with_uppercase: bool,
+    pub with_lowercase: bool,
+}
+
+/// A per-class minimum-count policy layered on top of `PasswordClasses`,
+/// used by `Generator::generate_password_with_policy` to reserve "at least
+/// N of this class" slots before filling the rest by round robin
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PasswordPolicy {
+    pub classes: PasswordClasses,
+    pub min_symbols: u8,
+    pub min_digits: u8,
+    pub min_upper: u8,
+    pub min_lower: u8,
+}
+
+/// Casing applied to each word of a generated passphrase
+#[derive(Clone, Copy, Debug)]
+pub enum WordCase {
+    Lower,
+    Upper,
+    Capitalize,
+}
+
+/// A positional layout assigning a `PasswordCharRule` to each character of
+/// the password, inspired by the Master Password algorithm's templates
+#[derive(Clone, Debug)]
+pub struct Template(Vec<PasswordCharRule>);
+
+impl Template {
+    /// Parses a template pattern string into a sequence of character class
+    /// rules, one per position:
+    /// - `n`/`N` a digit
+    /// - `o`/`O` a symbol
+    /// - any other uppercase letter an uppercase character
+    /// - any other letter a lowercase character
+    ///
+    /// ## Arguments
+    /// - `pattern` the template pattern, e.g. `"CvccnoCvccn"`
+    ///
+    /// ## Returns
+    /// The parsed template
+    pub fn parse(pattern: &str) -> Self {
+        Template(pattern.chars().map(Self::class_for).collect())
+    }
+
+    fn class_for(c: char) -> PasswordCharRule {
+        match c {
+            'n' | 'N' => PasswordCharRule::Digit,
+            'o' | 'O' => PasswordCharRule::Symbols,
+            c if c.is_uppercase() => PasswordCharRule::Upper,
+            _ => PasswordCharRule::Lower,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, PasswordCharRule> {
+        self.0.iter()
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub enum InsertDirection {
     Front,
     Back,
 }
+
+/// A labeled strength rating derived from a bit-entropy estimate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StrengthRating {
+    VeryWeak,
+    Weak,
+    Reasonable,
+    Strong,
+    VeryStrong,
+}
+
+impl StrengthRating {
+    /// Maps a bit-entropy estimate to a labeled rating using standard
+    /// thresholds
+    pub fn from_bits(bits: f64) -> Self {
+        if bits < 28.0 {
+            StrengthRating::VeryWeak
+        } else if bits < 36.0 {
+            StrengthRating::Weak
+        } else if bits < 60.0 {
+            StrengthRating::Reasonable
+        } else if bits < 128.0 {
+            StrengthRating::Strong
+        } else {
+            StrengthRating::VeryStrong
+        }
+    }
+}
+
+impl std::fmt::Display for StrengthRating {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            StrengthRating::VeryWeak => "Very Weak",
+            StrengthRating::Weak => "Weak",
+            StrengthRating::Reasonable => "Reasonable",
+            StrengthRating::Strong => "Strong",
+            StrengthRating::VeryStrong => "Very Strong",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+/// A Shannon-style entropy-bits estimate of a password's strength, along
+/// with its labeled rating
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PasswordStrength {
+    pub bits: f64,
+    pub rating: StrengthRating,
+}
+
+impl PasswordStrength {
+    pub fn from_bits(bits: f64) -> Self {
+        Self {
+            bits,
+            rating: StrengthRating::from_bits(bits),
+        }
+    }
+}
+
+/// Approximate trigram (order-2 Markov) weights for pronounceable
+/// password generation, modeled after classic GPW-style generators. For
+/// letters `c1`, `c2`, `c3` (all `a..=z`),
+/// `TRIGRAM_WEIGHTS[c1 * 676 + c2 * 26 + c3]` holds a phonotactic weight
+/// for `c3` following the `c1c2` context: vowel/consonant alternation is
+/// favored, a third consonant in a row is suppressed unless `c1c2c3` is a
+/// known English cluster (`str`, `thr`, ...) or ends in a doubled
+/// consonant (`c2 == c3`, as in `btt`), a letter repeating itself a third
+/// time in a row gets the minimum weight of 1 (no weight in the table is
+/// 0), common English digraphs
+/// (`th`, `st`, `nd`, `ea`, ...) are boosted, `q` is followed almost
+/// exclusively by `u`, and both the starting letter `c1` and each target
+/// letter `c3` are biased toward common English letter frequencies.
+/// Flattened to a single array to keep it a plain embedded table rather
+/// than a nested one. Declared `static` rather than `const` so the table
+/// lives in one place instead of being copied into every use site.
+pub static TRIGRAM_WEIGHTS: [u32; 17576] = [
+1, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 2, 14, 1016, 7, 6, 366, 560, 1, 48, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 258, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 132, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 120, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 2, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 2, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 258, 1016, 7, 6, 20, 560, 1, 2, 240, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 144, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 258, 1016, 7, 120, 20, 560, 1, 2, 13, 8, 402, 600, 6, 1, 20, 21, 546, 224, 3, 8, 1, 6, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 114, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 5, 9, 258, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 360, 21, 546, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 366, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 378, 546, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 366, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 546, 224, 3, 8, 1, 6, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 2, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 2, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 2, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 2, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 2, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 258, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 132, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 120, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 2, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 2, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 2, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 240, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 144, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 402, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 114, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 360, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 378, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 546, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 2, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 2, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 2, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 2, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 258, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 132, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 120, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 2, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 2, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 2, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 240, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 144, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 402, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 114, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 360, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 378, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 546, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 2, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 2, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 2, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 2, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 2, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 132, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 120, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 2, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 2, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 2, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 240, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 144, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 402, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 114, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 360, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 378, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 546, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 2, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 2, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 2, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 2, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 2, 14, 1016, 7, 6, 366, 560, 1, 48, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 258, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+164, 120, 224, 344, 1, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 132, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 120, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 2, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 2, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 258, 1016, 7, 6, 20, 560, 1, 2, 240, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 144, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 258, 1016, 7, 120, 20, 560, 1, 2, 13, 8, 402, 600, 6, 1, 20, 21, 546, 224, 3, 8, 1, 6, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 114, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 5, 9, 258, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 360, 21, 546, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 366, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 378, 546, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 366, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 546, 224, 3, 8, 1, 6, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 2, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 2, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 2, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 2, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 2, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 258, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 120, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 2, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 2, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 2, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 240, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 144, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 402, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 114, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 360, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 378, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 546, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 2, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 2, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 2, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 2, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 2, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 258, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 132, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 2, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 2, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 2, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 240, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 144, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 402, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 114, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 360, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 378, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 546, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 2, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 2, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 2, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 2, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 2, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 258, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 132, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 120, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 2, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 2, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 240, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 144, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 402, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 114, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 360, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 378, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 546, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 2, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 2, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 2, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 2, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 2, 14, 1016, 7, 6, 366, 560, 1, 48, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 258, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 132, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 120, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 2, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 1, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 2, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 258, 1016, 7, 6, 20, 560, 1, 2, 240, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 144, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 258, 1016, 7, 120, 20, 560, 1, 2, 13, 8, 402, 600, 6, 1, 20, 21, 546, 224, 3, 8, 1, 6, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 114, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 5, 9, 258, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 360, 21, 546, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 366, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 378, 546, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 366, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 546, 224, 3, 8, 1, 6, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 2, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 2, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 2, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 2, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 2, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 258, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 132, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 120, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 2, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 2, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 240, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 144, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 402, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 114, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 360, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 378, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 546, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 2, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 2, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 2, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 2, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 2, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 258, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 132, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 120, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 2, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 2, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 240, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 144, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 402, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 114, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 360, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 378, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 546, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 2, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 2, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 2, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 2, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 2, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 258, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 132, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 120, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 2, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 2, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 2, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 144, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 402, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 114, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 360, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 378, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 546, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 2, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 2, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 2, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 2, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 2, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 258, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 132, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 120, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 2, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 2, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 2, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 240, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 402, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 114, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 360, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 378, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 546, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 2, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 2, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 2, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 2, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 2, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 258, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 240, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 132, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 120, 1, 560, 1, 1, 160, 1, 1, 600, 1, 1, 240, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 2, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 2, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 2, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 240, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 144, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 114, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 360, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 378, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 546, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 2, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 2, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 2, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 2, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 2, 14, 1016, 7, 6, 366, 560, 1, 48, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 258, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 132, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 120, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 2, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 2, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 258, 1016, 7, 6, 20, 560, 1, 2, 240, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 144, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 258, 1016, 7, 120, 20, 560, 1, 2, 13, 8, 402, 600, 6, 1, 20, 21, 546, 224, 3, 8, 1, 6, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 1, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 114, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 5, 9, 258, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 360, 21, 546, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 366, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 378, 546, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 366, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 546, 224, 3, 8, 1, 6, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 2, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 2, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 2, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 2, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 2, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 258, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 132, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 120, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 2, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 2, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 2, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 240, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 144, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 402, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 360, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 378, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 546, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 2, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 2, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 2, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 2, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 2,
+2, 8, 16, 32, 3, 16, 16, 48, 14, 8, 8, 32, 16, 48, 1, 8, 8, 48, 48, 72, 1, 8, 16, 8, 16, 8,
+64, 2, 1, 1, 96, 1, 1, 1, 56, 1, 1, 1, 1, 1, 56, 1, 1, 1, 1, 1, 16, 1, 1, 1, 1, 1,
+64, 1, 2, 1, 96, 1, 1, 1, 56, 1, 1, 1, 1, 1, 56, 1, 1, 1, 1, 1, 16, 1, 1, 1, 1, 1,
+64, 1, 1, 24, 96, 1, 1, 1, 56, 1, 1, 1, 1, 1, 56, 1, 1, 1, 1, 1, 16, 1, 1, 1, 1, 1,
+16, 8, 16, 32, 72, 16, 16, 48, 1, 8, 8, 32, 16, 48, 1, 8, 8, 48, 48, 72, 1, 8, 16, 8, 16, 8,
+64, 1, 1, 1, 96, 12, 1, 1, 56, 1, 1, 1, 1, 1, 56, 1, 1, 1, 1, 1, 16, 1, 1, 1, 1, 1,
+64, 1, 1, 1, 96, 1, 12, 1, 56, 1, 1, 1, 1, 1, 56, 1, 1, 1, 1, 1, 16, 1, 1, 1, 1, 1,
+64, 1, 1, 1, 96, 1, 1, 2, 56, 1, 1, 1, 1, 1, 56, 1, 1, 1, 1, 1, 16, 1, 1, 1, 1, 1,
+2, 8, 16, 32, 24, 16, 16, 48, 2, 8, 8, 32, 16, 48, 14, 8, 8, 48, 48, 72, 1, 8, 16, 8, 16, 8,
+64, 1, 1, 1, 96, 1, 1, 1, 56, 2, 1, 1, 1, 1, 56, 1, 1, 1, 1, 1, 16, 1, 1, 1, 1, 1,
+64, 1, 1, 1, 96, 1, 1, 1, 56, 1, 2, 1, 1, 1, 56, 1, 1, 1, 1, 1, 16, 1, 1, 1, 1, 1,
+64, 1, 1, 1, 96, 1, 1, 1, 56, 1, 1, 24, 1, 1, 56, 1, 1, 1, 1, 1, 16, 1, 1, 1, 1, 1,
+64, 1, 1, 1, 96, 1, 1, 1, 56, 1, 1, 1, 12, 1, 56, 1, 1, 1, 1, 1, 16, 1, 1, 1, 1, 1,
+64, 1, 1, 1, 96, 1, 1, 1, 56, 1, 1, 1, 1, 36, 56, 1, 1, 1, 1, 1, 16, 1, 1, 1, 1, 1,
+16, 8, 16, 32, 3, 16, 16, 48, 1, 8, 8, 32, 16, 48, 42, 8, 8, 48, 48, 72, 4, 8, 16, 8, 16, 8,
+64, 1, 1, 1, 96, 1, 1, 1, 56, 1, 1, 1, 1, 1, 56, 6, 1, 1, 1, 1, 16, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+64, 1, 1, 1, 96, 1, 1, 1, 56, 1, 1, 1, 1, 1, 56, 1, 1, 36, 1, 1, 16, 1, 1, 1, 1, 1,
+64, 1, 1, 1, 96, 1, 1, 1, 56, 1, 1, 1, 1, 1, 56, 1, 1, 1, 36, 1, 16, 1, 1, 1, 1, 1,
+64, 1, 1, 1, 96, 1, 1, 1, 56, 1, 1, 1, 1, 1, 56, 1, 1, 1, 1, 54, 16, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+64, 1, 1, 1, 96, 1, 1, 1, 56, 1, 1, 1, 1, 1, 56, 1, 1, 1, 1, 1, 16, 2, 1, 1, 1, 1,
+64, 1, 1, 1, 96, 1, 1, 1, 56, 1, 1, 1, 1, 1, 56, 1, 1, 1, 1, 1, 16, 1, 2, 1, 1, 1,
+64, 1, 1, 1, 96, 1, 1, 1, 56, 1, 1, 1, 1, 1, 56, 1, 1, 1, 1, 1, 16, 1, 1, 2, 1, 1,
+64, 1, 1, 1, 96, 1, 1, 1, 56, 1, 1, 1, 1, 1, 56, 1, 1, 1, 1, 1, 16, 1, 1, 1, 2, 1,
+64, 1, 1, 1, 96, 1, 1, 1, 56, 1, 1, 1, 1, 1, 56, 1, 1, 1, 1, 1, 16, 1, 1, 1, 1, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 2, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 258, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 132, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 120, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 2, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 2, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 2, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 240, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 144, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 402, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 114, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 378, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 546, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 2, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 2, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 2, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 2, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 2, 1, 1016, 1, 1, 244, 560, 1, 1, 1, 1, 1, 600, 1, 1, 240, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 258, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 132, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 120, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 2, 560, 1, 1, 1, 1, 1, 600, 1, 1, 240, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 2, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 2, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 240, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 144, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 402, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 160, 1, 1, 600, 114, 1, 240, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 360, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 240, 1, 546, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 2, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 2, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 2, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 2, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 2, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 258, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 132, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 120, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 2, 560, 1, 1, 1, 1, 1, 600, 1, 1, 240, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 2, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 2, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 240, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 144, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 402, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 114, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 360, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 378, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 2, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 2, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 2, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 2, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 2, 14, 1016, 7, 6, 366, 560, 1, 48, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 258, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 132, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 120, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 2, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 2, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 258, 1016, 7, 6, 20, 560, 1, 2, 240, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 144, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 258, 1016, 7, 120, 20, 560, 1, 2, 13, 8, 402, 600, 6, 1, 20, 21, 546, 224, 3, 8, 1, 6, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 114, 1, 20, 21, 30, 224, 3, 8, 1, 6, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 5, 9, 258, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 360, 21, 546, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 366, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 378, 546, 224, 3, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 366, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 546, 224, 3, 8, 1, 6, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 1, 80, 192, 16, 160, 8,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 2, 8, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 2, 1, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 2, 6, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 2, 1,
+656, 5, 9, 14, 1016, 7, 6, 20, 560, 1, 2, 13, 8, 22, 600, 6, 1, 20, 21, 30, 224, 3, 8, 1, 6, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 2, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 258, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 132, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 120, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 2, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 2, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 2, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 240, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 144, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 402, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 114, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 360, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 378, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 546, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 2, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 2, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 2, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 2, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 258, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 132, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 120, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 2, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 2, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 2, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 240, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 144, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 402, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 114, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 360, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 378, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 546, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 2, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 2, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 2, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 2, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 258, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 132, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 120, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 2, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 2, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 2, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 240, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 144, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 402, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 114, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 360, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 378, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 546, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 2, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 2, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 2, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 2, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 258, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 132, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 120, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 2, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 2, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 2, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 240, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 144, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 402, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 114, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 360, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 378, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 546, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 2, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 2, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 2, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 2,
+2, 120, 224, 344, 31, 176, 160, 488, 140, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 2, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 2, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 258, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 762, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 132, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 120, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 2, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 2, 16, 64, 320, 192, 536, 150, 152, 8, 480, 504, 728, 7, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 2, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 2, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 240, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 144, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 402, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+164, 120, 224, 344, 31, 176, 160, 488, 17, 16, 64, 320, 192, 536, 450, 152, 8, 480, 504, 728, 56, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 114, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 900, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 360, 1, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 378, 1, 224, 1, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 546, 224, 1, 1, 1, 1, 1,
+20, 120, 224, 344, 254, 176, 160, 488, 17, 16, 64, 320, 192, 536, 18, 152, 8, 480, 504, 728, 2, 80, 192, 16, 160, 8,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 2, 1, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 2, 1, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 2, 1, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 2, 1,
+656, 1, 1, 1, 1016, 1, 1, 1, 560, 1, 1, 1, 1, 1, 600, 1, 1, 1, 1, 1, 224, 1, 1, 1, 1, 1,
+];